@@ -5,6 +5,36 @@ use ink_lang as ink;
 #[ink::contract]
 mod erc20 {
 
+    use ink_prelude::string::String;
+
+    /// Errors that can occur while interacting with the contract.
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        /// The account does not hold enough balance for the requested transfer.
+        InsufficientBalance,
+        /// The spender's allowance is lower than the requested amount.
+        InsufficientAllowance,
+        /// The account already has an active lock and cannot lock again.
+        AlreadyLocked,
+        /// The account does not have any locked balance to unlock.
+        NoActiveLock,
+        /// The lock is still in effect and cannot be released yet.
+        LockNotExpired,
+        /// The receipt signature did not recover to the configured bridge key.
+        InvalidSignature,
+        /// The receipt has already been redeemed once (replay protection).
+        ReceiptAlreadyUsed,
+        /// The caller is not the contract owner and lacks the required rights.
+        NotOwner,
+        /// Increasing the allowance would overflow `Balance`.
+        AllowanceOverflow,
+    }
+
+    /// Convenience alias returned by the mutating messages other than
+    /// `approve`, which returns a plain `bool`.
+    pub type Result<T> = core::result::Result<T, Error>;
+
     /// Defines the storage of your contract.
     /// Add new fields to the below struct in order
     /// to add new static storage fields to your contract.
@@ -16,6 +46,22 @@ mod erc20 {
         balances: ink_storage::collections::HashMap<AccountId, Balance>,
         // Approval spender on behalf of the message's sender
         allowances: ink_storage::collections::HashMap<(AccountId, AccountId), Balance>,
+        // Human readable token name, shown by wallets and explorers
+        name: Option<String>,
+        // Short ticker symbol for the token
+        symbol: Option<String>,
+        // Number of decimal places used when rendering balances
+        decimals: u8,
+        // Principal that each account has currently locked
+        lock_balance: ink_storage::collections::HashMap<AccountId, Balance>,
+        // Block timestamp at which each account's lock may be released
+        lock_time: ink_storage::collections::HashMap<AccountId, Timestamp>,
+        // Compressed secp256k1 public key of the trusted bridge signer
+        bridge_pubkey: [u8; 33],
+        // Receipt hashes that have already been redeemed, guarding against replay
+        used_receipts: ink_storage::collections::HashMap<Hash, ()>,
+        // Account allowed to mint, burn and hand over ownership
+        owner: AccountId,
     }
 
     #[ink(event)]
@@ -38,10 +84,38 @@ mod erc20 {
         value: Balance,
     }
 
+    #[ink(event)]
+    pub struct Lock {
+        #[ink(topic)]
+        account: AccountId,
+        value: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Unlock {
+        #[ink(topic)]
+        account: AccountId,
+        value: Balance,
+    }
+
     impl Erc20 {
         #[cfg(not(feature = "ink-as-dependency"))]
         #[ink(constructor)]
         pub fn new(inital_supply: Balance) -> Self {
+            Self::new_with_metadata(inital_supply, None, None, 18, [0u8; 33])
+        }
+
+        /// Creates a token that also carries the `name`/`symbol`/`decimals`
+        /// metadata expected by wallets and explorers.
+        #[cfg(not(feature = "ink-as-dependency"))]
+        #[ink(constructor)]
+        pub fn new_with_metadata(
+            inital_supply: Balance,
+            name: Option<String>,
+            symbol: Option<String>,
+            decimals: u8,
+            bridge_pubkey: [u8; 33],
+        ) -> Self {
             let caller = Self::env().caller();
             let mut newbalances = ink_storage::collections::HashMap::new();
             newbalances.insert(caller, inital_supply);
@@ -56,6 +130,14 @@ mod erc20 {
                 total_supply: inital_supply,
                 balances: newbalances,
                 allowances: ink_storage::collections::HashMap::new(),
+                name: name,
+                symbol: symbol,
+                decimals: decimals,
+                lock_balance: ink_storage::collections::HashMap::new(),
+                lock_time: ink_storage::collections::HashMap::new(),
+                bridge_pubkey: bridge_pubkey,
+                used_receipts: ink_storage::collections::HashMap::new(),
+                owner: caller,
             }
         }
 
@@ -70,7 +152,22 @@ mod erc20 {
         }
 
         #[ink(message)]
-        pub fn transfer(&mut self, to: AccountId, value: Balance) -> bool {
+        pub fn token_name(&self) -> Option<String> {
+            self.name.clone()
+        }
+
+        #[ink(message)]
+        pub fn token_symbol(&self) -> Option<String> {
+            self.symbol.clone()
+        }
+
+        #[ink(message)]
+        pub fn token_decimals(&self) -> u8 {
+            self.decimals
+        }
+
+        #[ink(message)]
+        pub fn transfer(&mut self, to: AccountId, value: Balance) -> Result<()> {
             let caller = self.env().caller();
             self.transfer_from_to(caller, to, value)
         }
@@ -88,38 +185,242 @@ mod erc20 {
             true
         }
 
+        #[ink(message)]
+        pub fn increase_allowance(&mut self, spender: AccountId, delta: Balance) -> Result<()> {
+            let owner = self.env().caller();
+            let allowance = self.allowance_of_or_zero(&owner, &spender);
+            let new_allowance = allowance
+                .checked_add(delta)
+                .ok_or(Error::AllowanceOverflow)?;
+            self.allowances.insert((owner, spender), new_allowance);
+            self.env().emit_event(Approval {
+                owner: owner,
+                spender: spender,
+                value: new_allowance,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn decrease_allowance(&mut self, spender: AccountId, delta: Balance) -> Result<()> {
+            let owner = self.env().caller();
+            let allowance = self.allowance_of_or_zero(&owner, &spender);
+            let new_allowance = allowance.saturating_sub(delta);
+            self.allowances.insert((owner, spender), new_allowance);
+            self.env().emit_event(Approval {
+                owner: owner,
+                spender: spender,
+                value: new_allowance,
+            });
+
+            Ok(())
+        }
+
         #[ink(message)]
         pub fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
             self.allowance_of_or_zero(&owner, &spender)
         }
 
         #[ink(message)]
-        pub fn transfer_from(&mut self, from: AccountId, to: AccountId, value: Balance) -> bool {
+        pub fn transfer_from(&mut self, from: AccountId, to: AccountId, value: Balance) -> Result<()> {
             let caller = self.env().caller();
             let allowance = self.allowance_of_or_zero(&from, &caller);
             if allowance < value {
-                return false;
+                return Err(Error::InsufficientAllowance);
             }
 
-            let transfer_result = self.transfer_from_to(from, to, value);
-            if !transfer_result {
-                return false;
-            }
+            self.transfer_from_to(from, to, value)?;
 
             self.allowances.insert((from, caller), allowance - value);
 
-            true
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn lock(&mut self, value: Balance, duration: Timestamp) -> Result<()> {
+            let caller = self.env().caller();
+
+            if self.lock_balance_of_or_zero(&caller) > 0 {
+                return Err(Error::AlreadyLocked);
+            }
+
+            let caller_balance = self.balance_of_or_zero(&caller);
+            if caller_balance < value {
+                return Err(Error::InsufficientBalance);
+            }
+
+            // Move the principal out of the spendable balance and record it.
+            self.balances.insert(caller, caller_balance - value);
+            self.lock_balance.insert(caller, value);
+            self.lock_time
+                .insert(caller, self.env().block_timestamp() + duration);
+
+            // Mint an equal amount back to the caller's spendable balance.
+            let spendable = self.balance_of_or_zero(&caller);
+            self.balances.insert(caller, spendable + value);
+            self.total_supply += value;
+
+            self.env().emit_event(Lock {
+                account: caller,
+                value: value,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn unlock(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+
+            let locked = self.lock_balance_of_or_zero(&caller);
+            if locked == 0 {
+                return Err(Error::NoActiveLock);
+            }
+
+            if self.env().block_timestamp() < self.lock_time_of_or_zero(&caller) {
+                return Err(Error::LockNotExpired);
+            }
+
+            // Returning the principal and burning the equal amount minted at
+            // lock time cancel out on the caller's balance, so only the minted
+            // supply is removed.
+            self.total_supply -= locked;
+            self.lock_balance.insert(caller, 0);
+            self.lock_time.insert(caller, 0);
+
+            self.env().emit_event(Unlock {
+                account: caller,
+                value: locked,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn mint_with_receipt(
+            &mut self,
+            recipient: AccountId,
+            amount: Balance,
+            nonce: u64,
+            signature: [u8; 65],
+        ) -> Result<()> {
+            use ink_env::hash::Keccak256;
+            use scale::Encode;
+
+            // Reconstruct the message that the bridge signed off-chain.
+            let payload = (recipient, amount, nonce).encode();
+            let mut msg_hash = <[u8; 32]>::default();
+            self.env().hash_bytes::<Keccak256>(&payload, &mut msg_hash);
+
+            // Replay/reuse protection is the core invariant: reject any receipt
+            // that has already been redeemed before doing any further work.
+            let receipt = Hash::from(msg_hash);
+            if self.used_receipts.contains_key(&receipt) {
+                return Err(Error::ReceiptAlreadyUsed);
+            }
+
+            // Recover the signer and make sure it is the configured bridge key.
+            let mut recovered = [0u8; 33];
+            self.env()
+                .ecdsa_recover(&signature, &msg_hash, &mut recovered)
+                .map_err(|_| Error::InvalidSignature)?;
+            if recovered != self.bridge_pubkey {
+                return Err(Error::InvalidSignature);
+            }
+
+            self.used_receipts.insert(receipt, ());
+
+            let balance = self.balance_of_or_zero(&recipient);
+            self.balances.insert(recipient, balance + amount);
+            self.total_supply += amount;
+
+            self.env().emit_event(Transfer {
+                from: None,
+                to: Some(recipient),
+                value: amount,
+            });
+
+            Ok(())
+        }
+
+        /// Burns `value` of the caller's own tokens, signalling the opposite
+        /// bridge side to release the corresponding funds. This is
+        /// permissionless: any holder may burn to exit.
+        #[ink(message)]
+        pub fn bridge_burn(&mut self, value: Balance) -> Result<()> {
+            let caller = self.env().caller();
+            let balance = self.balance_of_or_zero(&caller);
+            if balance < value {
+                return Err(Error::InsufficientBalance);
+            }
+
+            self.balances.insert(caller, balance - value);
+            self.total_supply -= value;
+
+            self.env().emit_event(Transfer {
+                from: Some(caller),
+                to: None,
+                value: value,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn mint(&mut self, to: AccountId, value: Balance) -> Result<()> {
+            self.ensure_owner()?;
+
+            let balance = self.balance_of_or_zero(&to);
+            self.balances.insert(to, balance + value);
+            self.total_supply += value;
+
+            self.env().emit_event(Transfer {
+                from: None,
+                to: Some(to),
+                value: value,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn burn(&mut self, from: AccountId, value: Balance) -> Result<()> {
+            self.ensure_owner()?;
+
+            let balance = self.balance_of_or_zero(&from);
+            if balance < value {
+                return Err(Error::InsufficientBalance);
+            }
+
+            self.balances.insert(from, balance - value);
+            self.total_supply -= value;
+
+            self.env().emit_event(Transfer {
+                from: Some(from),
+                to: None,
+                value: value,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<()> {
+            self.ensure_owner()?;
+            self.owner = new_owner;
+            Ok(())
         }
 
         fn balance_of_or_zero(&self, owner: &AccountId) -> Balance {
             *self.balances.get(owner).unwrap_or(&0)
         }
 
-        fn transfer_from_to(&mut self, from: AccountId, to: AccountId, value: Balance) -> bool {
+        fn transfer_from_to(&mut self, from: AccountId, to: AccountId, value: Balance) -> Result<()> {
             let from_balance = self.balance_of_or_zero(&from);
 
             if from_balance < value {
-                return false;
+                return Err(Error::InsufficientBalance);
             }
 
             self.balances.insert(from, from_balance - value);
@@ -132,12 +433,27 @@ mod erc20 {
                 to: Some(to),
                 value: value,
             });
-            true
+            Ok(())
         }
 
         fn allowance_of_or_zero(&self, owner: &AccountId, spender: &AccountId) -> Balance {
             *self.allowances.get(&(*owner, *spender)).unwrap_or(&0)
         }
+
+        fn ensure_owner(&self) -> Result<()> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            Ok(())
+        }
+
+        fn lock_balance_of_or_zero(&self, owner: &AccountId) -> Balance {
+            *self.lock_balance.get(owner).unwrap_or(&0)
+        }
+
+        fn lock_time_of_or_zero(&self, owner: &AccountId) -> Timestamp {
+            *self.lock_time.get(owner).unwrap_or(&0)
+        }
     }
 
     /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
@@ -170,9 +486,12 @@ mod erc20 {
         fn transfer_works() {
             let mut contract = Erc20::new(100);
             assert_eq!(contract.balance_of(AccountId::from([0x1; 32])), 100);
-            assert!(contract.transfer(AccountId::from([0x0; 32]), 10));
+            assert_eq!(contract.transfer(AccountId::from([0x0; 32]), 10), Ok(()));
             assert_eq!(contract.balance_of(AccountId::from([0x0; 32])), 10);
-            assert!(!contract.transfer(AccountId::from([0x0; 32]), 100));
+            assert_eq!(
+                contract.transfer(AccountId::from([0x0; 32]), 100),
+                Err(Error::InsufficientBalance)
+            );
         }
 
         #[ink::test]
@@ -180,7 +499,10 @@ mod erc20 {
             let mut contract = Erc20::new(100);
             assert_eq!(contract.balance_of(AccountId::from([0x1; 32])), 100);
             contract.approve(AccountId::from([0x1; 32]), 20);
-            contract.transfer_from(AccountId::from([0x1; 32]), AccountId::from([0x0; 32]), 10);
+            assert_eq!(
+                contract.transfer_from(AccountId::from([0x1; 32]), AccountId::from([0x0; 32]), 10),
+                Ok(())
+            );
             assert_eq!(contract.balance_of(AccountId::from([0x0; 32])), 10);
         }
 
@@ -191,13 +513,113 @@ mod erc20 {
             contract.approve(AccountId::from([0x1; 32]), 200);
             assert_eq!(contract.allowance(AccountId::from([0x1; 32]), AccountId::from([0x1; 32])), 200);
 
-            assert!(contract.transfer_from(AccountId::from([0x1; 32]), AccountId::from([0x0; 32]), 50));
+            assert_eq!(
+                contract.transfer_from(AccountId::from([0x1; 32]), AccountId::from([0x0; 32]), 50),
+                Ok(())
+            );
             assert_eq!(contract.balance_of(AccountId::from([0x0; 32])), 50);
             assert_eq!(contract.allowance(AccountId::from([0x1; 32]), AccountId::from([0x1; 32])), 150);
 
-            assert!(!contract.transfer_from(AccountId::from([0x1; 32]), AccountId::from([0x0; 32]), 100));
+            assert_eq!(
+                contract.transfer_from(AccountId::from([0x1; 32]), AccountId::from([0x0; 32]), 100),
+                Err(Error::InsufficientAllowance)
+            );
             assert_eq!(contract.balance_of(AccountId::from([0x0; 32])), 50);
             assert_eq!(contract.allowance(AccountId::from([0x1; 32]), AccountId::from([0x1; 32])), 150);
         }
+
+        #[ink::test]
+        fn mint_with_receipt_rejects_replay() {
+            use scale::Encode;
+
+            let mut contract = Erc20::new(100);
+            let recipient = AccountId::from([0x2; 32]);
+            let amount = 50;
+            let nonce = 1u64;
+
+            // Reproduce the receipt hash the contract derives and mark it used,
+            // simulating a receipt that has already been redeemed.
+            let payload = (recipient, amount, nonce).encode();
+            let mut msg_hash = <[u8; 32]>::default();
+            ink_env::hash_bytes::<ink_env::hash::Keccak256>(&payload, &mut msg_hash);
+            contract.used_receipts.insert(Hash::from(msg_hash), ());
+
+            assert_eq!(
+                contract.mint_with_receipt(recipient, amount, nonce, [0u8; 65]),
+                Err(Error::ReceiptAlreadyUsed)
+            );
+        }
+
+        #[ink::test]
+        fn lock_and_unlock_works() {
+            let mut contract = Erc20::new(100);
+            let caller = AccountId::from([0x1; 32]);
+
+            assert_eq!(contract.lock(40, 10), Ok(()));
+            // The minted amount keeps the spendable balance whole but grows supply.
+            assert_eq!(contract.balance_of(caller), 100);
+            assert_eq!(contract.total_supply(), 140);
+
+            // A second lock while one is active is rejected.
+            assert_eq!(contract.lock(10, 10), Err(Error::AlreadyLocked));
+
+            // Unlocking before the lock expires is rejected.
+            assert_eq!(contract.unlock(), Err(Error::LockNotExpired));
+
+            // Advance the clock past expiry and unlock.
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(10);
+            assert_eq!(contract.unlock(), Ok(()));
+            assert_eq!(contract.balance_of(caller), 100);
+            assert_eq!(contract.total_supply(), 100);
+        }
+
+        #[ink::test]
+        fn non_owner_can_bridge_burn_own_balance() {
+            let accounts = ink_env::test::default_accounts::<ink_env::DefaultEnvironment>()
+                .expect("off-chain environment should have been initialized already");
+            let mut contract = Erc20::new(100);
+            // Hand ownership away so the caller is a plain holder, not the owner.
+            assert_eq!(contract.transfer_ownership(accounts.bob), Ok(()));
+            assert_eq!(contract.bridge_burn(40), Ok(()));
+            assert_eq!(contract.balance_of(accounts.alice), 60);
+            assert_eq!(contract.total_supply(), 60);
+        }
+
+        #[ink::test]
+        fn mint_works() {
+            let mut contract = Erc20::new(100);
+            assert_eq!(contract.mint(AccountId::from([0x0; 32]), 50), Ok(()));
+            assert_eq!(contract.balance_of(AccountId::from([0x0; 32])), 50);
+            assert_eq!(contract.total_supply(), 150);
+        }
+
+        #[ink::test]
+        fn burn_works() {
+            let mut contract = Erc20::new(100);
+            assert_eq!(contract.burn(AccountId::from([0x1; 32]), 40), Ok(()));
+            assert_eq!(contract.balance_of(AccountId::from([0x1; 32])), 60);
+            assert_eq!(contract.total_supply(), 60);
+        }
+
+        #[ink::test]
+        fn non_owner_cannot_mint() {
+            let accounts = ink_env::test::default_accounts::<ink_env::DefaultEnvironment>()
+                .expect("off-chain environment should have been initialized already");
+            let mut contract = Erc20::new(100);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.mint(accounts.bob, 50), Err(Error::NotOwner));
+            assert_eq!(contract.burn(accounts.alice, 10), Err(Error::NotOwner));
+            assert_eq!(contract.transfer_ownership(accounts.bob), Err(Error::NotOwner));
+        }
+
+        #[ink::test]
+        fn transfer_ownership_works() {
+            let accounts = ink_env::test::default_accounts::<ink_env::DefaultEnvironment>()
+                .expect("off-chain environment should have been initialized already");
+            let mut contract = Erc20::new(100);
+            assert_eq!(contract.transfer_ownership(accounts.bob), Ok(()));
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.mint(accounts.bob, 10), Ok(()));
+        }
     }
 }